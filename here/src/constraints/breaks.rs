@@ -146,7 +146,7 @@ fn demote_unassigned_breaks(ctx: &mut SolutionContext) {
     ctx.ignored.extend(breaks_set.into_iter());
 }
 
-/// Removes breaks without location served separately.They are left-overs
+/// Removes breaks without location served separately. They are left-overs
 /// from ruin methods when original job is removed, but break is kept.
 fn remove_orphan_breaks(ctx: &mut SolutionContext) {
     let breaks_set = ctx.routes.iter_mut().fold(HashSet::new(), |mut acc, rc: &mut RouteContext| {
@@ -156,10 +156,7 @@ fn remove_orphan_breaks(ctx: &mut SolutionContext) {
                 let current = activity.place.location;
 
                 if let Some(break_job) = as_break_job(activity) {
-                    // TODO support multiple places for break
-                    assert_eq!(break_job.places.len(), 1);
-
-                    if prev != current && break_job.places.first().and_then(|p| p.location).is_none() {
+                    if prev != current && is_orphan_break(&break_job) {
                         breaks.insert(activity.job.as_ref().unwrap().clone());
                     }
                 }
@@ -213,7 +210,22 @@ fn is_correct_vehicle(rc: &RouteContext, target_id: &String, target_shift: usize
 
 fn is_time(rc: &RouteContext, break_job: &Single) -> bool {
     let arrival = rc.route.tour.end().map_or(0., |end| end.schedule.arrival);
-    break_job.places.first().unwrap().times.iter().any(|t| t.start < arrival)
+    is_time_at_arrival(break_job, arrival)
+}
+
+/// A break is still feasible to insert as long as at least one of its candidate places has
+/// a time window starting before `arrival` (the arrival at the route's end); which of those places minimizes detour cost is
+/// then decided by the insertion evaluator, not here. Pulled out as a free function over
+/// plain data so it can be tested without needing a full `RouteContext`/`Tour` fixture.
+fn is_time_at_arrival(break_job: &Single, arrival: f64) -> bool {
+    break_job.places.iter().any(|place| place.times.iter().any(|t| t.start < arrival))
+}
+
+/// A break is orphaned (left with nowhere meaningful to be reinserted) only when none of
+/// its candidate places pin a location: if at least one place is location-bound, the break
+/// is still eligible for (re)insertion there even if it isn't the place it currently sits at.
+fn is_orphan_break(break_job: &Single) -> bool {
+    break_job.places.iter().all(|p| p.location.is_none())
 }
 
 //endregion
@@ -0,0 +1,40 @@
+use super::*;
+use core::models::common::TimeWindow;
+use core::models::problem::Place;
+
+fn break_single(places: Vec<Place>) -> Single {
+    let mut dimens = Dimensions::default();
+    dimens.set_value("type", "break".to_string());
+    dimens.set_value("vehicle_id", "vehicle_1".to_string());
+    dimens.set_value("shift_index", 0_usize);
+
+    Single { places, dimens }
+}
+
+fn place(location: Option<usize>, start: f64, end: f64) -> Place {
+    Place { location, duration: 0., times: vec![TimeWindow { start, end }] }
+}
+
+#[test]
+fn is_time_at_arrival_accepts_break_when_any_candidate_place_opens_before_route_end() {
+    // the break module must not reject a break just because its first candidate place's
+    // time window opens after the route ends: with several candidate places, it's feasible
+    // as long as *any* of them opens before that arrival, leaving the actual pick (the one
+    // minimizing detour cost) to the insertion evaluator, not to this feasibility check.
+    let break_job = break_single(vec![place(Some(1), 100., 200.), place(Some(2), 0., 10.)]);
+
+    assert!(is_time_at_arrival(&break_job, 50.));
+    // route ends before any candidate place's window opens: not feasible anywhere
+    assert!(!is_time_at_arrival(&break_job, 0.));
+}
+
+#[test]
+fn is_orphan_break_requires_every_candidate_place_to_be_locationless() {
+    let locationless_break = break_single(vec![place(None, 0., 100.)]);
+    let mixed_break = break_single(vec![place(None, 0., 100.), place(Some(3), 0., 100.)]);
+    let location_bound_break = break_single(vec![place(Some(3), 0., 100.)]);
+
+    assert!(is_orphan_break(&locationless_break));
+    assert!(!is_orphan_break(&mixed_break));
+    assert!(!is_orphan_break(&location_bound_break));
+}
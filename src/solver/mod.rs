@@ -0,0 +1,3 @@
+//! Contains the metaheuristic which drives the search for better solutions.
+
+pub mod mutation;
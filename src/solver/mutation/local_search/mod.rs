@@ -0,0 +1,35 @@
+//! Contains local search moves which try to improve an already complete solution by
+//! rearranging jobs between or within routes.
+
+mod priority_replace;
+mod route_split;
+mod swap_star;
+pub use self::priority_replace::PriorityReplace;
+pub use self::route_split::RouteSplit;
+pub use self::swap_star::SwapStar;
+
+pub(crate) use super::route_by_actor;
+
+use crate::construction::states::{RouteContext, SolutionContext};
+use crate::models::problem::Job;
+use crate::models::Problem;
+use std::sync::Arc;
+
+/// A trait which specifies a way to improve a complete solution in place.
+pub trait LocalSearch {
+    /// Tries to improve `solution_ctx`, returning `true` if a strictly improving move
+    /// was found and applied.
+    fn explore(&self, problem: &Problem, solution_ctx: &mut SolutionContext) -> bool;
+}
+
+/// Collects the distinct jobs currently served by a route.
+pub(super) fn jobs_of(route_ctx: &RouteContext) -> Vec<Arc<Job>> {
+    let mut seen = std::collections::HashSet::new();
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.clone())
+        .filter(|job| seen.insert(Arc::as_ptr(job)))
+        .collect()
+}
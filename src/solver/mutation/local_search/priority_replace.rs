@@ -0,0 +1,144 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/local_search/priority_replace_test.rs"]
+mod priority_replace_test;
+
+use super::{jobs_of, route_by_actor, LocalSearch};
+use crate::construction::heuristics::InsertionEvaluator;
+use crate::construction::states::{RouteContext, SolutionContext};
+use crate::models::common::Dimensions;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use std::sync::Arc;
+
+/// Priority assumed for jobs which don't carry an explicit `"priority"` dimension. Lower
+/// values are more important, mirroring how `unassigned`'s reason codes are just small ints.
+const DEFAULT_PRIORITY: i32 = 1;
+
+/// A local search move which guarantees that premium/urgent jobs are not silently left in
+/// `unassigned` while a vehicle is busy serving optional ones. For each high-priority job
+/// stuck unassigned, it looks for the cheapest-to-evict set of lower-priority jobs on some
+/// route whose removal would make the high-priority job feasible there, commits that
+/// eviction, inserts the high-priority job, and pushes the evicted jobs back into
+/// `required` so they can be picked up again by the regular recreate pass (possibly
+/// somewhere cheaper, possibly not at all).
+///
+/// The move is accepted whenever it lets a strictly more important job in, even if it
+/// raises the raw routing cost slightly: the priority weighting always outranks it.
+pub struct PriorityReplace {
+    evaluator: InsertionEvaluator,
+}
+
+impl PriorityReplace {
+    /// Creates a new instance of `PriorityReplace`.
+    pub fn new() -> Self {
+        Self { evaluator: InsertionEvaluator::default() }
+    }
+}
+
+impl Default for PriorityReplace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalSearch for PriorityReplace {
+    fn explore(&self, problem: &Problem, solution_ctx: &mut SolutionContext) -> bool {
+        let mut candidates = solution_ctx.unassigned.keys().cloned().collect::<Vec<_>>();
+        candidates.sort_by_key(priority_of);
+
+        candidates.into_iter().filter(|job| priority_of(job) < DEFAULT_PRIORITY).any(|job| {
+            let routes = solution_ctx.routes.iter().cloned().collect::<Vec<_>>();
+            routes.iter().any(|route_ctx| self.try_replace_in(problem, solution_ctx, route_ctx, &job))
+        })
+    }
+}
+
+impl PriorityReplace {
+    /// Tries to evict the cheapest set of lower-priority jobs from `route_ctx` to make
+    /// room for `job`. Returns `true` and commits the change only if `job` ends up
+    /// feasibly inserted; otherwise restores every evicted job before returning `false`.
+    fn try_replace_in(
+        &self,
+        problem: &Problem,
+        solution_ctx: &mut SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Arc<Job>,
+    ) -> bool {
+        let job_priority = priority_of(job);
+        let actor = route_ctx.route.actor.clone();
+
+        let mut evictable = jobs_of(route_ctx)
+            .into_iter()
+            .filter(|candidate| priority_of(candidate) > job_priority)
+            .map(|candidate| (self.evaluator.removal_cost(problem, solution_ctx, &candidate), candidate))
+            .collect::<Vec<_>>();
+        evictable.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+        let mut evicted = Vec::new();
+        let mut feasible = false;
+        for (_, candidate) in evictable {
+            self.evaluator.remove_job(solution_ctx, &candidate);
+            evicted.push(candidate);
+
+            // the route mutated above is copy-on-write: re-fetch the live route rather
+            // than re-checking feasibility against the pre-eviction snapshot
+            let current_route = match route_by_actor(solution_ctx, &actor) {
+                Some(route_ctx) => route_ctx,
+                None => break,
+            };
+
+            if self.evaluator.evaluate_job(problem, &current_route, job).is_some() {
+                feasible = true;
+                break;
+            }
+        }
+
+        if !feasible {
+            self.restore_evicted(problem, solution_ctx, evicted);
+            return false;
+        }
+
+        solution_ctx.unassigned.remove(job);
+
+        if !self.evaluator.insert_job(problem, solution_ctx, job.clone()) {
+            // the feasibility check above can only be a promise, not a guarantee (the live
+            // route may have shifted again between the check and the commit): if the
+            // actual insertion still fails, return `job` to `unassigned` (with reason code 0)
+            // and reinsert the evicted jobs via `restore_evicted`, instead of leaving `job`
+            // removed from `unassigned` with nowhere to go
+            solution_ctx.unassigned.insert(job.clone(), 0);
+            self.restore_evicted(problem, solution_ctx, evicted);
+            return false;
+        }
+
+        // lower priority jobs go back through the regular recreate pass rather than being
+        // force-reinserted here, so a cheaper slot elsewhere can still be found for them
+        solution_ctx.required.extend(evicted);
+
+        true
+    }
+
+    /// Reinserts each evicted job, pushing any that fail to reinsert into `required`
+    /// instead of discarding them: a job must always end up somewhere rather than vanish
+    /// from the solution entirely.
+    fn restore_evicted(&self, problem: &Problem, solution_ctx: &mut SolutionContext, evicted: Vec<Arc<Job>>) {
+        let failed = evicted
+            .into_iter()
+            .filter(|candidate| !self.evaluator.insert_job(problem, solution_ctx, candidate.clone()))
+            .collect::<Vec<_>>();
+        solution_ctx.required.extend(failed);
+    }
+}
+
+fn priority_of(job: &Arc<Job>) -> i32 {
+    let dimens = match job.as_ref() {
+        Job::Single(single) => &single.dimens,
+        Job::Multi(multi) => &multi.dimens,
+    };
+
+    priority_from_dimens(dimens)
+}
+
+fn priority_from_dimens(dimens: &Dimensions) -> i32 {
+    dimens.get_value::<i32>("priority").copied().unwrap_or(DEFAULT_PRIORITY)
+}
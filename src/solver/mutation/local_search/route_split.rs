@@ -0,0 +1,172 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/local_search/route_split_test.rs"]
+mod route_split_test;
+
+use super::{route_by_actor, LocalSearch};
+use crate::construction::heuristics::InsertionEvaluator;
+use crate::construction::states::{RouteContext, SolutionContext};
+use crate::models::solution::Actor;
+use crate::models::Problem;
+use std::sync::Arc;
+
+/// A local search move which offloads the tail of an overloaded route onto a fresh,
+/// currently unused vehicle from the registry. This helps escape local optima where one
+/// vehicle ends up doing most of the work while others stay idle, which a move confined
+/// to a single route can never fix.
+///
+/// The split point is chosen by scanning the tour for the position that best balances
+/// the number of activities between the two halves; demand and time are not taken into
+/// account. Because `BreakHardActivityConstraint`
+/// locks a break to a specific vehicle and shift, any activity that no longer satisfies
+/// the hard constraints of the route it ended up on (most commonly a break tied to the
+/// vehicle that stayed behind) is pulled back into `required` so the conditional break
+/// module can reassign or drop it on the next pass.
+pub struct RouteSplit {
+    evaluator: InsertionEvaluator,
+}
+
+impl RouteSplit {
+    /// Creates a new instance of `RouteSplit`.
+    pub fn new() -> Self {
+        Self { evaluator: InsertionEvaluator::default() }
+    }
+}
+
+impl Default for RouteSplit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalSearch for RouteSplit {
+    fn explore(&self, problem: &Problem, solution_ctx: &mut SolutionContext) -> bool {
+        let routes = solution_ctx.routes.iter().cloned().collect::<Vec<_>>();
+
+        routes.iter().any(|route_ctx| self.try_split(problem, solution_ctx, route_ctx))
+    }
+}
+
+impl RouteSplit {
+    fn try_split(&self, problem: &Problem, solution_ctx: &mut SolutionContext, route_ctx: &RouteContext) -> bool {
+        let activities = route_ctx.route.tour.all_activities().collect::<Vec<_>>();
+        // need at least two non-depot activities for a split to be meaningful
+        if activities.len() < 4 {
+            return false;
+        }
+
+        let split_index = match best_split_point(activities.len()) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let tail_jobs = activities[split_index..activities.len() - 1]
+            .iter()
+            .filter_map(|activity| activity.job.clone())
+            .collect::<Vec<_>>();
+
+        if tail_jobs.is_empty() {
+            return false;
+        }
+
+        let new_route_ctx = match solution_ctx.registry.next_route() {
+            Some(route_ctx) => route_ctx,
+            // no idle vehicle available to receive the tail of the route
+            None => return false,
+        };
+
+        let original_actor = route_ctx.route.actor.clone();
+        let new_actor = new_route_ctx.route.actor.clone();
+
+        let cost_before =
+            self.evaluator.route_cost(problem, route_ctx) + self.evaluator.route_cost(problem, &new_route_ctx);
+
+        solution_ctx.routes.insert(new_route_ctx);
+        tail_jobs.iter().for_each(|job| self.evaluator.remove_job(solution_ctx, job));
+
+        let reinserted = tail_jobs.iter().all(|job| match route_by_actor(solution_ctx, &new_actor) {
+            Some(route_ctx) => self.evaluator.insert_job_into(problem, solution_ctx, &route_ctx, job.clone()),
+            None => false,
+        });
+
+        if !reinserted {
+            // at least one tail job could not be placed on the split-off route: abandon
+            // the split, but the jobs must still land somewhere rather than vanish from
+            // the solution, so send them through the usual recreate pass instead
+            tail_jobs.iter().for_each(|job| self.evaluator.remove_job(solution_ctx, job));
+            self.free_new_route(solution_ctx, &new_actor);
+            solution_ctx.required.extend(tail_jobs);
+            return false;
+        }
+
+        // route mutation is copy-on-write, so both routes must be looked up again by
+        // actor rather than compared against the pre-mutation `RouteContext`s above
+        let cost_after = route_by_actor(solution_ctx, &original_actor)
+            .map(|route_ctx| self.evaluator.route_cost(problem, &route_ctx))
+            .unwrap_or(0.)
+            + route_by_actor(solution_ctx, &new_actor)
+                .map(|route_ctx| self.evaluator.route_cost(problem, &route_ctx))
+                .unwrap_or(0.);
+
+        if cost_after >= cost_before {
+            tail_jobs.iter().for_each(|job| self.evaluator.remove_job(solution_ctx, job));
+            let restored = tail_jobs.iter().all(|job| self.evaluator.insert_job(problem, solution_ctx, job.clone()));
+            if !restored {
+                solution_ctx.required.extend(tail_jobs);
+            }
+            self.free_new_route(solution_ctx, &new_actor);
+            return false;
+        }
+
+        return_infeasible_jobs(problem, solution_ctx);
+
+        true
+    }
+
+    fn free_new_route(&self, solution_ctx: &mut SolutionContext, actor: &Arc<Actor>) {
+        if let Some(route_ctx) = route_by_actor(solution_ctx, actor) {
+            solution_ctx.registry.free_route(&route_ctx);
+            solution_ctx.routes.remove(&route_ctx);
+        }
+    }
+}
+
+/// Picks the split index (in `2..=activity_count - 2`) that best balances the number of
+/// activities between the two halves of a tour of `activity_count` activities (including
+/// its two depot ends), so that both halves keep at least one non-depot activity.
+fn best_split_point(activity_count: usize) -> Option<usize> {
+    let total = activity_count - 2;
+    if total < 2 {
+        return None;
+    }
+
+    (2..=activity_count - 2).min_by_key(|&index| {
+        let before = index - 1;
+        let after = total - before;
+        (before as i64 - after as i64).abs()
+    })
+}
+
+/// Re-checks every activity still carrying a job against the hard constraints of the
+/// route it is currently on, pushing back to `required` any that no longer hold now that
+/// the tour has been split across two vehicles.
+fn return_infeasible_jobs(problem: &Problem, solution_ctx: &mut SolutionContext) {
+    let infeasible = solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            route_ctx
+                .route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.clone())
+                .filter(|job| !problem.constraint.is_feasible_in_place(route_ctx, job))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    infeasible.iter().for_each(|job| {
+        solution_ctx.routes.iter().for_each(|route_ctx| route_ctx.route_mut().tour.remove(job));
+    });
+
+    solution_ctx.required.extend(infeasible);
+}
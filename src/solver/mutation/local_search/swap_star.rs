@@ -0,0 +1,221 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/local_search/swap_star_test.rs"]
+mod swap_star_test;
+
+use super::{jobs_of, route_by_actor, LocalSearch};
+use crate::construction::heuristics::InsertionEvaluator;
+use crate::construction::states::{RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::{Activity, Actor};
+use crate::models::Problem;
+use std::sync::Arc;
+
+/// How many cheapest insertion positions are cached per job/route pair before falling
+/// back to a full route scan.
+const CACHED_POSITIONS: usize = 3;
+
+/// A local search move which, for a pair of routes, removes one job from each and
+/// reinserts it into the *other* route at its own best feasible position (which may
+/// differ from the slot it vacated). This is strictly more powerful than a plain swap,
+/// which would just exchange the two jobs in their existing positions.
+///
+/// All `HardActivityConstraint`s (including break vehicle/shift locking) are enforced by
+/// the same evaluator used during recreate, so a swap can never place a job somewhere
+/// infeasible. Any break left dangling by a swap is cleaned up by the usual
+/// `accept_solution_state` pass afterwards, not here.
+pub struct SwapStar {
+    evaluator: InsertionEvaluator,
+}
+
+impl SwapStar {
+    /// Creates a new instance of `SwapStar`.
+    pub fn new() -> Self {
+        Self { evaluator: InsertionEvaluator::default() }
+    }
+}
+
+impl Default for SwapStar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalSearch for SwapStar {
+    fn explore(&self, problem: &Problem, solution_ctx: &mut SolutionContext) -> bool {
+        let routes = solution_ctx.routes.iter().cloned().collect::<Vec<_>>();
+
+        for (i, route_a) in routes.iter().enumerate() {
+            for route_b in routes.iter().skip(i + 1) {
+                if self.try_swap(problem, solution_ctx, route_a, route_b) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl SwapStar {
+    fn try_swap(
+        &self,
+        problem: &Problem,
+        solution_ctx: &mut SolutionContext,
+        route_a: &RouteContext,
+        route_b: &RouteContext,
+    ) -> bool {
+        let jobs_a = jobs_of(route_a);
+        let jobs_b = jobs_of(route_b);
+
+        // rank candidate pairs by a cheap estimate (the cached top insertion positions)
+        // before paying for a full remove+reinsert+evaluate cycle
+        let mut candidates = jobs_a
+            .iter()
+            .flat_map(|job_a| jobs_b.iter().map(move |job_b| (job_a.clone(), job_b.clone())))
+            .map(|(job_a, job_b)| {
+                let positions_a = self.evaluator.top_positions(problem, route_b, &job_a, CACHED_POSITIONS);
+                let positions_b = self.evaluator.top_positions(problem, route_a, &job_b, CACHED_POSITIONS);
+                let estimate_a = positions_a.first().map(|(cost, _)| cost);
+                let estimate_b = positions_b.first().map(|(cost, _)| cost);
+
+                (pair_estimate(estimate_a, estimate_b), job_a, job_b, positions_a, positions_b)
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(a, ..), (b, ..)| a.partial_cmp(b).unwrap());
+
+        candidates.into_iter().any(|(_, job_a, job_b, positions_a, positions_b)| {
+            self.apply_if_improving(
+                problem,
+                solution_ctx,
+                route_a,
+                route_b,
+                &job_a,
+                &job_b,
+                &positions_a,
+                &positions_b,
+            )
+        })
+    }
+
+    /// Removes `job_a` and `job_b` from their routes and reinserts each into the other
+    /// route, trying the cached top positions first and only falling back to a full route
+    /// scan once all of them have become infeasible (e.g. after the partner job vacated
+    /// its own slot). Keeps the change only if the combined cost delta is negative; on any
+    /// rejection both jobs are restored to exactly the route and position they started in,
+    /// never left to whatever slot generic best-insertion would otherwise have picked.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_if_improving(
+        &self,
+        problem: &Problem,
+        solution_ctx: &mut SolutionContext,
+        route_a: &RouteContext,
+        route_b: &RouteContext,
+        job_a: &Arc<Job>,
+        job_b: &Arc<Job>,
+        positions_a: &[(Cost, usize)],
+        positions_b: &[(Cost, usize)],
+    ) -> bool {
+        let actor_a = route_a.route.actor.clone();
+        let actor_b = route_b.route.actor.clone();
+        let original_a = activity_of(route_a, job_a);
+        let original_b = activity_of(route_b, job_b);
+
+        let saved = self.evaluator.removal_cost(problem, solution_ctx, job_a)
+            + self.evaluator.removal_cost(problem, solution_ctx, job_b);
+
+        self.evaluator.remove_job(solution_ctx, job_a);
+        self.evaluator.remove_job(solution_ctx, job_b);
+
+        let reinserted_a = self.insert_with_cache(problem, solution_ctx, &actor_b, job_a, positions_a);
+        let reinserted_b = self.insert_with_cache(problem, solution_ctx, &actor_a, job_b, positions_b);
+
+        let accepted = reinserted_a
+            && reinserted_b
+            && {
+                let gained = self.evaluator.removal_cost(problem, solution_ctx, job_a)
+                    + self.evaluator.removal_cost(problem, solution_ctx, job_b);
+                gained - saved < 0.
+            };
+
+        if accepted {
+            return true;
+        }
+
+        if reinserted_a {
+            self.evaluator.remove_job(solution_ctx, job_a);
+        }
+        if reinserted_b {
+            self.evaluator.remove_job(solution_ctx, job_b);
+        }
+
+        restore_activity(solution_ctx, &actor_a, original_a);
+        restore_activity(solution_ctx, &actor_b, original_b);
+
+        false
+    }
+
+    /// Tries the cached candidate positions (valid for the route as it stood before the
+    /// partner job was removed) first; since removing the partner job can only free up
+    /// capacity/time, a cached position that was feasible before is re-validated here, and
+    /// only once every cached candidate has been rejected does this fall back to asking the
+    /// evaluator to scan the rest of `target_actor`'s own route for a new best slot. Never
+    /// falls back to a solution-wide search: a swap must only ever touch the two routes it
+    /// was invoked on.
+    fn insert_with_cache(
+        &self,
+        problem: &Problem,
+        solution_ctx: &mut SolutionContext,
+        target_actor: &Arc<Actor>,
+        job: &Arc<Job>,
+        cached_positions: &[(Cost, usize)],
+    ) -> bool {
+        for &(_, index) in cached_positions {
+            if let Some(route_ctx) = route_by_actor(solution_ctx, target_actor) {
+                if self.evaluator.insert_job_at(problem, solution_ctx, &route_ctx, job, index) {
+                    return true;
+                }
+            }
+        }
+
+        match route_by_actor(solution_ctx, target_actor) {
+            Some(route_ctx) => self.evaluator.insert_job_into(problem, solution_ctx, &route_ctx, job.clone()),
+            None => false,
+        }
+    }
+}
+
+/// Combines the two independent per-job insertion estimates into a single ranking key for
+/// a candidate swap pair; a pair where either cache came up empty is ranked last rather
+/// than excluded, since it may still be worth a full scan once its partner is removed.
+fn pair_estimate(cost_a: Option<&Cost>, cost_b: Option<&Cost>) -> Cost {
+    match (cost_a, cost_b) {
+        (Some(a), Some(b)) => a + b,
+        _ => Cost::MAX,
+    }
+}
+
+/// Finds the tour index and a clone of the activity currently serving `job` on `route_ctx`.
+fn activity_of(route_ctx: &RouteContext, job: &Arc<Job>) -> Option<(usize, Activity)> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .enumerate()
+        .find(|(_, activity)| activity.job.as_ref().map_or(false, |activity_job| Arc::ptr_eq(activity_job, job)))
+        .map(|(index, activity)| (index, activity.clone()))
+}
+
+/// Puts a previously removed activity back at its original index on the live route for
+/// `actor`, bypassing the generic evaluator entirely so a declined swap can never end up
+/// relocating a job to some unrelated third route.
+fn restore_activity(solution_ctx: &SolutionContext, actor: &Arc<Actor>, original: Option<(usize, Activity)>) {
+    let (index, activity) = match original {
+        Some(pair) => pair,
+        None => return,
+    };
+
+    if let Some(route_ctx) = route_by_actor(solution_ctx, actor) {
+        route_ctx.route_mut().tour.insert(activity, index);
+    }
+}
@@ -0,0 +1,20 @@
+//! Contains mutation operators (ruin and recreate methods, local search moves) applied
+//! to a solution during refinement.
+
+pub mod local_search;
+pub mod recreate;
+
+use crate::construction::states::{RouteContext, SolutionContext};
+use crate::models::solution::Actor;
+use std::sync::Arc;
+
+/// Looks up the current `RouteContext` for a given actor in the live solution.
+///
+/// Route mutation (`remove_job`/`insert_job`) replaces a route's `Arc` via copy-on-write
+/// rather than mutating it in place, so a `RouteContext` captured before a mutation no
+/// longer matches anything in `solution_ctx.routes` by identity afterwards. Looking the
+/// route back up by its actor (which does not change across such mutations) is the only
+/// reliable way to get at the live state.
+pub(crate) fn route_by_actor(solution_ctx: &SolutionContext, actor: &Arc<Actor>) -> Option<RouteContext> {
+    solution_ctx.routes.iter().find(|route_ctx| Arc::ptr_eq(&route_ctx.route.actor, actor)).cloned()
+}
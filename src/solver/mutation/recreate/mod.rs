@@ -0,0 +1,14 @@
+//! Contains recreate methods which try to insert required jobs back into solution's routes.
+
+mod recreate_with_regret;
+pub use self::recreate_with_regret::RecreateWithRegret;
+
+use crate::construction::states::SolutionContext;
+use crate::models::Problem;
+
+/// A trait which specifies a way to insert required jobs back into solution's routes.
+pub trait Recreate {
+    /// Inserts jobs from `solution_ctx.required` into routes, moving jobs which cannot
+    /// be inserted feasibly into `solution_ctx.unassigned`.
+    fn run(&self, problem: &Problem, solution_ctx: &mut SolutionContext);
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_regret_test.rs"]
+mod recreate_with_regret_test;
+
+use super::Recreate;
+use crate::construction::heuristics::InsertionEvaluator;
+use crate::construction::states::{RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::Actor;
+use crate::models::Problem;
+use crate::solver::mutation::route_by_actor;
+use std::sync::Arc;
+
+/// Cost substituted for a job's i-th best insertion when fewer than `i` feasible
+/// insertions are found. Large enough to dominate any real cost difference so that
+/// "almost impossible to insert" always wins the regret comparison.
+const NO_INSERTION_PENALTY: Cost = 1e9;
+
+/// Inserts required jobs using a regret-k heuristic: instead of always taking the
+/// globally cheapest insertion, on each iteration it inserts the job whose insertion
+/// would be "regretted" the most if delayed, i.e. the one with the largest sum of gaps
+/// between its best insertion cost and each of its 2nd to `k`-th best ones across all routes.
+///
+/// With `k` equal to one, every job's regret is zero and the heuristic degenerates to
+/// plain cheapest insertion.
+pub struct RecreateWithRegret {
+    k: usize,
+    regret_coefficient: f64,
+    evaluator: InsertionEvaluator,
+}
+
+impl RecreateWithRegret {
+    /// Creates a new instance of `RecreateWithRegret`.
+    pub fn new(k: usize, regret_coefficient: f64) -> Self {
+        assert!(k >= 1, "regret-k heuristic requires k >= 1");
+        Self { k, regret_coefficient, evaluator: InsertionEvaluator::default() }
+    }
+}
+
+impl Default for RecreateWithRegret {
+    fn default() -> Self {
+        Self::new(2, 1.)
+    }
+}
+
+/// The cost of inserting a job's feasible into each route it could be inserted into,
+/// keyed by that route's actor so a single route's entry can be invalidated and
+/// recomputed without touching anything evaluated against the other routes.
+struct JobEvaluation {
+    job: Arc<Job>,
+    per_route: Vec<(Arc<Actor>, Cost)>,
+}
+
+impl JobEvaluation {
+    fn costs_sorted(&self, k: usize) -> Vec<Cost> {
+        let mut costs = self.per_route.iter().map(|(_, cost)| *cost).collect::<Vec<_>>();
+        costs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        costs.truncate(k);
+        while costs.len() < k {
+            costs.push(NO_INSERTION_PENALTY);
+        }
+        costs
+    }
+
+    fn best_cost(&self, k: usize) -> Cost {
+        self.costs_sorted(k)[0]
+    }
+
+    fn regret(&self, k: usize, regret_coefficient: f64) -> Cost {
+        regret_value(&self.costs_sorted(k), k, regret_coefficient)
+    }
+
+    /// Drops any cached cost for `route_ctx`'s actor and recomputes it, leaving every
+    /// other route's cached cost untouched.
+    fn refresh_route(&mut self, problem: &Problem, evaluator: &InsertionEvaluator, route_ctx: &RouteContext) {
+        self.per_route.retain(|(actor, _)| !Arc::ptr_eq(actor, &route_ctx.route.actor));
+
+        if let Some(cost) = evaluator.evaluate_job(problem, route_ctx, &self.job) {
+            self.per_route.push((route_ctx.route.actor.clone(), cost));
+        }
+    }
+}
+
+/// Regret value: the sum, for `i` from the 2nd to the `k`-th best cost, of the gap between
+/// that cost and the best one. Pulled out as a free function over plain costs so it can be
+/// tested without needing a `JobEvaluation` or any route/actor machinery.
+fn regret_value(costs_sorted: &[Cost], k: usize, regret_coefficient: f64) -> Cost {
+    let best = costs_sorted[0];
+    (1..k).map(|i| costs_sorted.get(i).copied().unwrap_or(NO_INSERTION_PENALTY) - best).sum::<Cost>()
+        * regret_coefficient
+}
+
+/// Finds the actor of whichever route currently carries `job`, used after an unrestricted
+/// `insert_job` fallback to find out where the job actually landed.
+fn route_containing_job(solution_ctx: &SolutionContext, job: &Arc<Job>) -> Option<Arc<Actor>> {
+    solution_ctx
+        .routes
+        .iter()
+        .find(|route_ctx| {
+            route_ctx.route.tour.all_activities().any(|activity| {
+                activity.job.as_ref().map_or(false, |activity_job| Arc::ptr_eq(activity_job, job))
+            })
+        })
+        .map(|route_ctx| route_ctx.route.actor.clone())
+}
+
+impl Recreate for RecreateWithRegret {
+    fn run(&self, problem: &Problem, solution_ctx: &mut SolutionContext) {
+        let mut evaluations: Vec<_> =
+            solution_ctx.required.iter().map(|job| self.evaluate_job(problem, solution_ctx, job)).collect();
+
+        while !evaluations.is_empty() {
+            // jobs with no feasible insertion at all are pushed to unassigned so the loop
+            // always makes progress and terminates
+            if let Some(pos) = evaluations.iter().position(|e| e.best_cost(self.k) >= NO_INSERTION_PENALTY) {
+                let evaluation = evaluations.remove(pos);
+                solution_ctx.required.retain(|job| !Arc::ptr_eq(job, &evaluation.job));
+                solution_ctx.unassigned.insert(evaluation.job, 0);
+                continue;
+            }
+
+            let best_idx = evaluations
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| {
+                    a.regret(self.k, self.regret_coefficient)
+                        .partial_cmp(&b.regret(self.k, self.regret_coefficient))
+                        .unwrap()
+                        // ties broken by smallest best cost
+                        .then_with(|| b.best_cost(self.k).partial_cmp(&a.best_cost(self.k)).unwrap())
+                })
+                .map(|(idx, _)| idx)
+                .unwrap();
+
+            let evaluation = evaluations.remove(best_idx);
+
+            solution_ctx.required.retain(|job| !Arc::ptr_eq(job, &evaluation.job));
+
+            // we already know, from our own cache, which route gives the job its best cost:
+            // insert it there directly instead of asking the evaluator to search again
+            let target_actor = evaluation
+                .per_route
+                .iter()
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+                .map(|(actor, _)| actor.clone());
+
+            let touched_route = target_actor.as_ref().and_then(|actor| {
+                let route_ctx = route_by_actor(solution_ctx, actor)?;
+                let inserted =
+                    self.evaluator.insert_job_into(problem, solution_ctx, &route_ctx, evaluation.job.clone());
+                inserted.then(|| actor.clone())
+            });
+
+            let touched_actor = match touched_route {
+                Some(actor) => Some(actor),
+                // cached route no longer accepts the job (state drifted since it was
+                // cached): fall back to a full, unrestricted search, then find out which
+                // route actually ended up taking the job so its cache can still be
+                // invalidated below instead of silently going stale
+                None => {
+                    let inserted = self.evaluator.insert_job(problem, solution_ctx, evaluation.job.clone());
+                    if inserted {
+                        route_containing_job(solution_ctx, &evaluation.job)
+                    } else {
+                        solution_ctx.unassigned.insert(evaluation.job, 0);
+                        None
+                    }
+                }
+            };
+
+            // only the route the job actually landed in can have changed its insertion
+            // cost for the remaining jobs: re-evaluate just that route's entry for each of
+            // them instead of rescanning every route from scratch
+            if let Some(actor) = touched_actor {
+                if let Some(route_ctx) = route_by_actor(solution_ctx, &actor) {
+                    evaluations
+                        .iter_mut()
+                        .for_each(|evaluation| evaluation.refresh_route(problem, &self.evaluator, &route_ctx));
+                }
+            }
+        }
+    }
+}
+
+impl RecreateWithRegret {
+    fn evaluate_job(&self, problem: &Problem, solution_ctx: &SolutionContext, job: &Arc<Job>) -> JobEvaluation {
+        let per_route = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                self.evaluator.evaluate_job(problem, route_ctx, job).map(|cost| (route_ctx.route.actor.clone(), cost))
+            })
+            .collect();
+
+        JobEvaluation { job: job.clone(), per_route }
+    }
+}
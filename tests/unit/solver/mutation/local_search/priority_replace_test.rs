@@ -0,0 +1,7 @@
+use super::*;
+
+#[test]
+fn defaults_to_default_priority_when_dimension_is_absent() {
+    let dimens = Dimensions::default();
+    assert_eq!(priority_from_dimens(&dimens), DEFAULT_PRIORITY);
+}
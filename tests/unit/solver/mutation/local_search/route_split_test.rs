@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn picks_the_midpoint_for_an_even_tour() {
+    assert_eq!(best_split_point(8), Some(4));
+}
+
+#[test]
+fn returns_none_when_tour_is_too_short_to_split() {
+    assert_eq!(best_split_point(3), None);
+}
+
+#[test]
+fn splits_the_smallest_splittable_tour_right_down_the_middle() {
+    // four activities (depot, job, job, depot) is the minimum a split makes sense for, and
+    // the only valid index leaves exactly one job on each side
+    assert_eq!(best_split_point(4), Some(2));
+}
+
+#[test]
+fn avoids_splitting_right_next_to_either_depot() {
+    let split = best_split_point(5).unwrap();
+    assert!(split > 1 && split < 5 - 2);
+}
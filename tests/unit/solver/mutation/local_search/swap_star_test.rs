@@ -0,0 +1,13 @@
+use super::*;
+
+#[test]
+fn sums_both_estimates_when_both_caches_are_non_empty() {
+    assert_eq!(pair_estimate(Some(&3.), Some(&4.)), 7.);
+}
+
+#[test]
+fn ranks_pair_last_when_either_cache_is_empty() {
+    assert_eq!(pair_estimate(None, Some(&4.)), Cost::MAX);
+    assert_eq!(pair_estimate(Some(&4.), None), Cost::MAX);
+    assert_eq!(pair_estimate(None, None), Cost::MAX);
+}
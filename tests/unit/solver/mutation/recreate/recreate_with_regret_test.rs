@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn k_equal_one_has_zero_regret() {
+    assert_eq!(regret_value(&[10., 20., 30.], 1, 1.), 0.);
+}
+
+#[test]
+fn regret_sums_gaps_to_every_alternative_up_to_k_and_applies_the_coefficient() {
+    let costs = [10., 15., 40.];
+    assert_eq!(regret_value(&costs, 3, 2.), ((15. - 10.) + (40. - 10.)) * 2.);
+}
+
+#[test]
+fn missing_alternatives_are_penalized_heavily() {
+    let regret = regret_value(&[10.], 3, 1.);
+    assert!(regret > NO_INSERTION_PENALTY);
+}
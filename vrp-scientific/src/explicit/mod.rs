@@ -0,0 +1,247 @@
+//! Reads problems defined with an explicit distance/duration matrix (the TSPLIB
+//! `EDGE_WEIGHT_TYPE: EXPLICIT` convention) rather than derived from node coordinates.
+//!
+//! Unlike `solomon` and `lilim`, which always compute travel cost from Euclidean
+//! coordinates, this format lets travel costs be asymmetric or come straight from a real
+//! road network: the matrix is supplied verbatim alongside per-node demands, time windows
+//! and vehicle capacity.
+
+use core::models::Problem;
+use std::io::{BufRead, BufReader, Read};
+
+/// Supported `EDGE_WEIGHT_FORMAT` layouts for an `EDGE_WEIGHT_SECTION`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MatrixFormat {
+    /// The full `n x n` matrix, one row per line.
+    Full,
+    /// Only the lower triangular part, excluding the diagonal (which is implicitly zero).
+    LowerRow,
+    /// Only the lower triangular part, including the diagonal.
+    LowerDiagRow,
+    /// Only the upper triangular part, excluding the diagonal (which is implicitly zero).
+    UpperRow,
+    /// Only the upper triangular part, including the diagonal.
+    UpperDiagRow,
+}
+
+impl MatrixFormat {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim() {
+            "FULL_MATRIX" => Ok(Self::Full),
+            "LOWER_ROW" => Ok(Self::LowerRow),
+            "LOWER_DIAG_ROW" => Ok(Self::LowerDiagRow),
+            "UPPER_ROW" => Ok(Self::UpperRow),
+            "UPPER_DIAG_ROW" => Ok(Self::UpperDiagRow),
+            _ => Err(format!("unknown EDGE_WEIGHT_FORMAT: '{}'", value)),
+        }
+    }
+}
+
+/// A node's demand and optional time window, as found in `DEMAND_SECTION` /
+/// `TIME_WINDOW_SECTION`.
+struct NodeInfo {
+    demand: i32,
+    time_window: Option<(f64, f64)>,
+}
+
+/// Reads a problem whose travel costs are given as an explicit distance matrix.
+///
+/// Returns the same internal [`Problem`] the `solomon` and `lilim` readers produce, so it
+/// can be fed into the same solver pipeline and benchmarked against real-world instances.
+pub fn read_explicit_format<R: Read>(reader: R) -> Result<Problem, String> {
+    let reader = BufReader::new(reader);
+    let mut dimension = None;
+    let mut capacity = None;
+    let mut matrix_format = None;
+    let mut matrix = Vec::new();
+    let mut demands = Vec::new();
+    let mut time_windows = Vec::new();
+    let mut depot = 0_usize;
+
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next() {
+        let line = line.map_err(|err| format!("cannot read line: {}", err))?;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once(':') {
+            match key.trim() {
+                "DIMENSION" => {
+                    dimension = Some(value.trim().parse::<usize>().map_err(|e| e.to_string())?);
+                }
+                "CAPACITY" => {
+                    capacity = Some(value.trim().parse::<i32>().map_err(|e| e.to_string())?);
+                }
+                "EDGE_WEIGHT_TYPE" if value.trim() != "EXPLICIT" => {
+                    return Err(format!("unsupported EDGE_WEIGHT_TYPE: '{}', expected EXPLICIT", value.trim()));
+                }
+                "EDGE_WEIGHT_FORMAT" => {
+                    matrix_format = Some(MatrixFormat::parse(value)?);
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        match line {
+            "EDGE_WEIGHT_SECTION" => {
+                let dimension = dimension.ok_or("DIMENSION must precede EDGE_WEIGHT_SECTION")?;
+                let format = matrix_format.ok_or("EDGE_WEIGHT_FORMAT must precede EDGE_WEIGHT_SECTION")?;
+                matrix = read_matrix(&mut lines, dimension, format)?;
+            }
+            "DEMAND_SECTION" => {
+                let dimension = dimension.ok_or("DIMENSION must precede DEMAND_SECTION")?;
+                demands = read_demands(&mut lines, dimension)?;
+            }
+            "TIME_WINDOW_SECTION" => {
+                let dimension = dimension.ok_or("DIMENSION must precede TIME_WINDOW_SECTION")?;
+                time_windows = read_time_windows(&mut lines, dimension)?;
+            }
+            "DEPOT_SECTION" => {
+                depot = lines
+                    .next()
+                    .ok_or("unexpected eof in DEPOT_SECTION")?
+                    .map_err(|e| e.to_string())?
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|e| e.to_string())?
+                    .saturating_sub(1);
+            }
+            "EOF" => break,
+            _ => {}
+        }
+    }
+
+    let dimension = dimension.ok_or("missing DIMENSION")?;
+    let capacity = capacity.ok_or("missing CAPACITY")?;
+
+    if matrix.len() != dimension {
+        return Err(format!("expected a {0}x{0} matrix, got {1} rows", dimension, matrix.len()));
+    }
+
+    let nodes = (0..dimension)
+        .map(|index| NodeInfo {
+            demand: demands.get(index).copied().unwrap_or(0),
+            time_window: time_windows.get(index).copied().flatten(),
+        })
+        .collect::<Vec<_>>();
+
+    build_problem(matrix, nodes, depot, capacity)
+}
+
+fn read_matrix(
+    lines: &mut std::io::Lines<BufReader<impl Read>>,
+    dimension: usize,
+    format: MatrixFormat,
+) -> Result<Vec<Vec<f64>>, String> {
+    let values = read_values(lines, match format {
+        MatrixFormat::Full => dimension * dimension,
+        MatrixFormat::LowerRow | MatrixFormat::UpperRow => dimension * (dimension - 1) / 2,
+        MatrixFormat::LowerDiagRow | MatrixFormat::UpperDiagRow => dimension * (dimension + 1) / 2,
+    })?;
+
+    let mut matrix = vec![vec![0.; dimension]; dimension];
+    let mut it = values.into_iter();
+
+    match format {
+        MatrixFormat::Full => {
+            for row in matrix.iter_mut() {
+                for cell in row.iter_mut() {
+                    *cell = it.next().ok_or("not enough values in EDGE_WEIGHT_SECTION")?;
+                }
+            }
+        }
+        MatrixFormat::LowerDiagRow => {
+            for row in 0..dimension {
+                for col in 0..=row {
+                    let value = it.next().ok_or("not enough values in EDGE_WEIGHT_SECTION")?;
+                    matrix[row][col] = value;
+                    matrix[col][row] = value;
+                }
+            }
+        }
+        MatrixFormat::LowerRow => {
+            for row in 0..dimension {
+                for col in 0..row {
+                    let value = it.next().ok_or("not enough values in EDGE_WEIGHT_SECTION")?;
+                    matrix[row][col] = value;
+                    matrix[col][row] = value;
+                }
+            }
+        }
+        MatrixFormat::UpperDiagRow => {
+            for row in 0..dimension {
+                for col in row..dimension {
+                    let value = it.next().ok_or("not enough values in EDGE_WEIGHT_SECTION")?;
+                    matrix[row][col] = value;
+                    matrix[col][row] = value;
+                }
+            }
+        }
+        MatrixFormat::UpperRow => {
+            for row in 0..dimension {
+                for col in (row + 1)..dimension {
+                    let value = it.next().ok_or("not enough values in EDGE_WEIGHT_SECTION")?;
+                    matrix[row][col] = value;
+                    matrix[col][row] = value;
+                }
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+fn read_demands(lines: &mut std::io::Lines<BufReader<impl Read>>, dimension: usize) -> Result<Vec<i32>, String> {
+    (0..dimension)
+        .map(|_| {
+            let line = lines.next().ok_or("unexpected eof in DEMAND_SECTION")?.map_err(|e| e.to_string())?;
+            let mut columns = line.split_whitespace();
+            columns.next(); // node id, positional only
+            columns.next().ok_or("missing demand value")?.parse::<i32>().map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+fn read_time_windows(
+    lines: &mut std::io::Lines<BufReader<impl Read>>,
+    dimension: usize,
+) -> Result<Vec<Option<(f64, f64)>>, String> {
+    (0..dimension)
+        .map(|_| {
+            let line = lines.next().ok_or("unexpected eof in TIME_WINDOW_SECTION")?.map_err(|e| e.to_string())?;
+            let mut columns = line.split_whitespace();
+            columns.next(); // node id, positional only
+            let start = columns.next().ok_or("missing time window start")?.parse::<f64>().map_err(|e| e.to_string())?;
+            let end = columns.next().ok_or("missing time window end")?.parse::<f64>().map_err(|e| e.to_string())?;
+            Ok(Some((start, end)))
+        })
+        .collect()
+}
+
+/// Reads whitespace/newline separated numeric values until `count` have been collected,
+/// allowing the matrix body to be wrapped across an arbitrary number of lines.
+fn read_values(lines: &mut std::io::Lines<BufReader<impl Read>>, count: usize) -> Result<Vec<f64>, String> {
+    let mut values = Vec::with_capacity(count);
+
+    while values.len() < count {
+        let line = lines.next().ok_or("unexpected eof while reading matrix values")?.map_err(|e| e.to_string())?;
+        for token in line.split_whitespace() {
+            values.push(token.parse::<f64>().map_err(|e| e.to_string())?);
+        }
+    }
+
+    Ok(values)
+}
+
+/// Builds the internal [`Problem`] from an explicit matrix plus per-node demand/time
+/// window data, reusing the same transport cost and constraint wiring as the coordinate
+/// based readers.
+fn build_problem(matrix: Vec<Vec<f64>>, nodes: Vec<NodeInfo>, depot: usize, capacity: i32) -> Result<Problem, String> {
+    let node_demands = nodes.into_iter().map(|node| (node.demand, node.time_window)).collect::<Vec<_>>();
+
+    crate::common::create_problem_with_matrix(matrix, node_demands, depot, capacity)
+}
@@ -6,6 +6,8 @@
 //!
 //! - **solomon**, see [Solomon benchmark](https://www.sintef.no/projectweb/top/vrptw/solomon-benchmark)
 //! - **lilim**, see [Li&Lim benchmark](https://www.sintef.no/projectweb/top/pdptw/li-lim-benchmark)
+//! - **explicit**, a TSPLIB-style format with an explicit distance/duration matrix instead
+//!   of coordinates, see [TSPLIB](http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/)
 
 #[cfg(test)]
 #[path = "../tests/helpers/mod.rs"]
@@ -17,6 +19,7 @@ pub mod helpers;
 mod known_problems_test;
 
 pub mod common;
+pub mod explicit;
 pub mod lilim;
 pub mod solomon;
 mod utils;
\ No newline at end of file
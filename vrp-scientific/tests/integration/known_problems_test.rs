@@ -0,0 +1,48 @@
+use crate::explicit::read_explicit_format;
+
+const EXPLICIT_PROBLEM: &str = r#"
+DIMENSION: 3
+CAPACITY: 10
+EDGE_WEIGHT_TYPE: EXPLICIT
+EDGE_WEIGHT_FORMAT: FULL_MATRIX
+EDGE_WEIGHT_SECTION
+ 0  2  4
+ 2  0  3
+ 4  3  0
+DEMAND_SECTION
+1 0
+2 3
+3 4
+DEPOT_SECTION
+1
+EOF
+"#;
+
+#[test]
+fn can_read_explicit_problem_with_full_matrix() {
+    let problem = read_explicit_format(EXPLICIT_PROBLEM.as_bytes()).unwrap();
+
+    assert_eq!(problem.jobs.size(), 2);
+}
+
+#[test]
+fn can_round_trip_explicit_problem_with_lower_diag_row_matrix() {
+    let triangular = EXPLICIT_PROBLEM
+        .replace("FULL_MATRIX", "LOWER_DIAG_ROW")
+        .replace(" 0  2  4\n 2  0  3\n 4  3  0", " 0\n 2  0\n 4  3  0");
+
+    let problem = read_explicit_format(triangular.as_bytes()).unwrap();
+
+    assert_eq!(problem.jobs.size(), 2);
+}
+
+#[test]
+fn can_round_trip_explicit_problem_with_lower_row_matrix_excluding_diagonal() {
+    // true TSPLIB LOWER_ROW: the diagonal is omitted entirely, not just zeroed
+    let triangular =
+        EXPLICIT_PROBLEM.replace("FULL_MATRIX", "LOWER_ROW").replace(" 0  2  4\n 2  0  3\n 4  3  0", " 2\n 4  3");
+
+    let problem = read_explicit_format(triangular.as_bytes()).unwrap();
+
+    assert_eq!(problem.jobs.size(), 2);
+}